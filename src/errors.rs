@@ -0,0 +1,36 @@
+use bitcoin::taproot::TaprootError;
+use bitcoin::Amount;
+use musig2::errors::KeyAggError;
+use thiserror::Error as ThisError;
+
+/// Top-level error type for the crate.
+#[derive(Debug, ThisError)]
+pub enum Error {
+    #[error("key aggregation error: {0}")]
+    KeyAgg(#[from] KeyAggError),
+
+    #[error("taproot construction error: {0}")]
+    Taproot(#[from] TaprootError),
+
+    /// Returned when a computed spend value would leave a TXO below the dust
+    /// threshold for its output type.
+    #[error("output value {value} is below the dust limit of {dust_limit}")]
+    BelowDustLimit { value: Amount, dust_limit: Amount },
+
+    /// Returned when building a [`crate::spend_info::split::SweepPackage`] from
+    /// members spending through more than one leaf path.
+    #[error("sweep package members must all spend the same leaf path")]
+    MixedSweepSpendPaths,
+
+    /// Returned when building a [`crate::spend_info::split::SweepPackage`] with no members.
+    #[error("sweep package must have at least one member")]
+    EmptySweepPackage,
+
+    /// Returned when constructing a [`crate::spend_info::split::SplitSpendInfo`] whose
+    /// `2 * block_delta` reclaim-path relative locktime wouldn't fit in the 16 bits BIP68
+    /// allows for a height-based `nSequence`.
+    #[error(
+        "block_delta {block_delta} doubled exceeds the 16-bit range of a BIP68 relative locktime"
+    )]
+    BlockDeltaTooLarge { block_delta: u16 },
+}
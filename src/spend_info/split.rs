@@ -1,12 +1,13 @@
 use bitcoin::{
     key::constants::SCHNORR_SIGNATURE_SIZE,
     opcodes::all::*,
-    taproot::{LeafVersion, TaprootSpendInfo},
+    taproot::{LeafVersion, TapLeafHash, TaprootSpendInfo},
     transaction::InputWeightPrediction,
-    Amount, ScriptBuf,
+    Amount, OutPoint, ScriptBuf, Sequence, Weight,
 };
 use musig2::KeyAggContext;
 use secp::Point;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     errors::Error,
@@ -14,6 +15,62 @@ use crate::{
     parties::{MarketMaker, Player},
 };
 
+/// Fixed per-transaction overhead weight: nVersion (4) + input count varint (1) +
+/// output count varint (1) + nLockTime (4) bytes at 4 weight units each, plus the
+/// 2 weight units for the segwit marker/flag (which are only counted once, not
+/// multiplied like the rest of the non-witness data).
+const TX_FIXED_WEIGHT: Weight = Weight::from_wu(10 * 4 + 2);
+
+/// Weight of a single P2TR destination output: value (8) + script length varint (1) +
+/// 34-byte witness v1 script, i.e. 43 non-witness bytes at 4 weight units each.
+const P2TR_OUTPUT_WEIGHT: Weight = Weight::from_wu(43 * 4);
+
+/// Standard dust limit for a P2TR output, as used by Bitcoin Core: the 43 vbyte cost
+/// of the output itself plus the ~67 vbyte assumed cost of spending it, at the default
+/// 3 sat/vB dust relay fee, i.e. `(43 + 67) * 3 = 330`.
+const P2TR_DUST_LIMIT: Amount = Amount::from_sat(330);
+
+/// Default additive feerate bump (sat per 1000 weight units, ~1 sat/vB) suggested
+/// per CPFP re-broadcast attempt.
+const FEERATE_BUMP_STEP_SAT_PER_KWU: u64 = 250;
+
+/// Re-broadcast scheduling metadata for a single spend leaf, used to plan a CPFP
+/// fee-bump of a stuck win/reclaim/sellback TX.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct LeafRescheduleInfo {
+    /// The first height at which this leaf's CSV requirement is satisfied.
+    pub(crate) earliest_spendable_height: u32,
+    /// Suggested additive bump to the feerate (sat per 1000 weight units) to apply
+    /// on each re-broadcast attempt while the original TX remains unconfirmed.
+    pub(crate) feerate_bump_step_sat_per_kwu: u64,
+}
+
+/// Identifies which of a [`SplitSpendInfo`]'s taproot leaves is being spent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SpendLeaf {
+    /// The player's win script, unlocked with their signature and ticket preimage.
+    Win,
+    /// The market maker's reclaim script, unlocked with their signature after `2*delta`.
+    Reclaim,
+    /// The market maker's sellback script, unlocked with their signature and the payout preimage.
+    Sellback,
+}
+
+/// Confirmation urgency tiers used to select a feerate from a [`FeeEstimator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConfirmationTarget {
+    /// The spend must confirm before a competing timelocked path becomes spendable.
+    BeforeTimelockExpiry,
+    /// No particular urgency; a background feerate is acceptable.
+    Normal,
+}
+
+/// Supplies feerates, in sat per 1000 weight units, for a given confirmation target,
+/// decoupling spend-value computation from however a caller actually sources feerates.
+pub(crate) trait FeeEstimator {
+    fn sat_per_1000_weight(&self, confirmation_target: ConfirmationTarget) -> u64;
+}
+
 /// Represents a taproot contract for a specific player's split TX payout output.
 /// This tree has three nodes:
 ///
@@ -34,6 +91,8 @@ pub(crate) struct SplitSpendInfo {
     win_script: ScriptBuf,
     reclaim_script: ScriptBuf,
     sellback_script: ScriptBuf,
+    anchor_script_pubkey: Option<ScriptBuf>,
+    block_delta: u16,
 }
 
 impl SplitSpendInfo {
@@ -42,7 +101,15 @@ impl SplitSpendInfo {
         market_maker: &MarketMaker,
         payout_value: Amount,
         block_delta: u16,
+        include_cpfp_anchor: bool,
     ) -> Result<SplitSpendInfo, Error> {
+        // The reclaim path's CSV requirement is `2 * block_delta`, which must itself fit
+        // a BIP68 height-based relative locktime (16 bits) to be representable as the
+        // `nSequence` baked into `reclaim_script` below.
+        if (block_delta as u32) * 2 > u16::MAX as u32 {
+            return Err(Error::BlockDeltaTooLarge { block_delta });
+        }
+
         let mut pubkeys = vec![market_maker.pubkey, winner.pubkey];
         pubkeys.sort();
         let untweaked_ctx = KeyAggContext::new(pubkeys)?;
@@ -95,7 +162,7 @@ impl SplitSpendInfo {
             .push_opcode(OP_CHECKSIG)
             .into_script();
 
-        let weighted_script_leaves = [
+        let weighted_script_leaves = vec![
             (2, sellback_script.clone()),
             (1, win_script.clone()),
             (1, reclaim_script.clone()),
@@ -106,6 +173,19 @@ impl SplitSpendInfo {
             weighted_script_leaves,
         )?;
 
+        // The split TX's CPFP anchor output, used by the market maker to bump the fee
+        // of a stuck win/reclaim/sellback TX after the split TX is presigned and fee
+        // rates have moved against it. This is deliberately a *separate* output from
+        // the payout output above, key-path-spendable by the market maker alone, at a
+        // fixed dust value: every script-path leaf in a taproot tree authorizes moving
+        // that output's *entire* value, so an anchor leaf added to the payout output's
+        // own tree would let the market maker (or, worse, anyone who later gained that
+        // leaf's witness) sweep the winner's and/or market maker's full stake under
+        // the guise of a fee bump. Isolating the anchor to its own dust output, as
+        // BOLT3/rust-lightning do, bounds what it can ever move to `P2TR_DUST_LIMIT`.
+        let anchor_script_pubkey = include_cpfp_anchor
+            .then(|| ScriptBuf::new_p2tr(secp256k1::SECP256K1, market_maker.pubkey.into(), None));
+
         let tweaked_ctx = untweaked_ctx.clone().with_taproot_tweak(
             tr_spend_info
                 .merkle_root()
@@ -122,6 +202,8 @@ impl SplitSpendInfo {
             win_script,
             reclaim_script,
             sellback_script,
+            anchor_script_pubkey,
+            block_delta,
         };
         Ok(split_spend_info)
     }
@@ -208,5 +290,755 @@ impl SplitSpendInfo {
         )
     }
 
+    /// Computes the input weight when spending this split TX's dedicated CPFP anchor
+    /// output, if one was included at construction. The anchor output is spent
+    /// directly via its key path (the market maker's own key), so the witness stack
+    /// is just a single schnorr signature — no script or control block involved.
+    pub(crate) fn anchor_input_weight(&self) -> Option<InputWeightPrediction> {
+        self.anchor_script_pubkey.as_ref()?;
+
+        // The witness stack for a TX spending the anchor output is: <mm_sig>
+        Some(InputWeightPrediction::new(0, [SCHNORR_SIGNATURE_SIZE]))
+    }
+
+    /// Computes [`LeafRescheduleInfo`] for `leaf`, assuming this split TX output
+    /// confirmed at `confirmation_height`: the next height a stuck claim through that
+    /// leaf becomes re-broadcastable, and how aggressively to bump its feerate via CPFP.
+    pub(crate) fn leaf_reschedule_info(
+        &self,
+        leaf: SpendLeaf,
+        confirmation_height: u32,
+    ) -> LeafRescheduleInfo {
+        let relative_delay = match leaf {
+            SpendLeaf::Win => self.block_delta as u32,
+            SpendLeaf::Reclaim => 2 * self.block_delta as u32,
+            SpendLeaf::Sellback => 0,
+        };
+
+        LeafRescheduleInfo {
+            earliest_spendable_height: confirmation_height.saturating_add(relative_delay),
+            feerate_bump_step_sat_per_kwu: FEERATE_BUMP_STEP_SAT_PER_KWU,
+        }
+    }
+
+    /// Computes the value that should be assigned to the single destination output of
+    /// a TX spending this split TX output via `leaf`, after subtracting the fee needed
+    /// to confirm at the feerate `fee_estimator` returns for `confirmation_target`.
+    ///
+    /// The total weight is the spending input's weight plus the fixed transaction
+    /// overhead plus the destination output's own (P2TR) weight plus `extra_output_weight`,
+    /// and the fee is that total
+    /// cost rounded up to the nearest satoshi. Returns [`Error::BelowDustLimit`] if
+    /// what's left over can't cover a standard P2TR output.
+    pub(crate) fn output_value_after_fee(
+        &self,
+        leaf: SpendLeaf,
+        fee_estimator: &dyn FeeEstimator,
+        confirmation_target: ConfirmationTarget,
+        extra_output_weight: Weight,
+    ) -> Result<Amount, Error> {
+        let input_weight = match leaf {
+            SpendLeaf::Win => self.input_weight_for_win_tx(),
+            SpendLeaf::Reclaim => self.input_weight_for_reclaim_tx(),
+            SpendLeaf::Sellback => self.input_weight_for_sellback_tx(),
+        };
+
+        let feerate_sat_per_kwu = fee_estimator.sat_per_1000_weight(confirmation_target);
+        let total_weight =
+            input_weight.weight() + TX_FIXED_WEIGHT + P2TR_OUTPUT_WEIGHT + extra_output_weight;
+        let fee = Amount::from_sat(
+            total_weight
+                .to_wu()
+                .saturating_mul(feerate_sat_per_kwu)
+                .div_ceil(1000),
+        );
+
+        let value = self.payout_value.checked_sub(fee).unwrap_or(Amount::ZERO);
+        if value < P2TR_DUST_LIMIT {
+            return Err(Error::BelowDustLimit {
+                value,
+                dust_limit: P2TR_DUST_LIMIT,
+            });
+        }
+
+        Ok(value)
+    }
+
+    /// Enumerates this split TX *payout* output's spend paths as standalone,
+    /// serializable [`SplitOutputDescriptor`]s, so a wallet without access to our
+    /// private fields can build and witness a sweep TX for any of them on its own.
+    /// `confirmation_height` is the height this split TX output confirmed at, used to
+    /// compute each leaf's [`LeafRescheduleInfo`]. This never includes the CPFP anchor
+    /// descriptor, since the anchor is a separate TX output with its own outpoint; use
+    /// [`SplitSpendInfo::anchor_descriptor`] for that one.
+    pub(crate) fn descriptors(
+        &self,
+        outpoint: OutPoint,
+        confirmation_height: u32,
+    ) -> Vec<SplitOutputDescriptor> {
+        vec![
+            SplitOutputDescriptor::Win(self.spend_details(
+                outpoint,
+                SpendLeaf::Win,
+                confirmation_height,
+            )),
+            SplitOutputDescriptor::Reclaim(self.spend_details(
+                outpoint,
+                SpendLeaf::Reclaim,
+                confirmation_height,
+            )),
+            SplitOutputDescriptor::Sellback(self.spend_details(
+                outpoint,
+                SpendLeaf::Sellback,
+                confirmation_height,
+            )),
+        ]
+    }
+
+    fn spend_details(
+        &self,
+        outpoint: OutPoint,
+        leaf: SpendLeaf,
+        confirmation_height: u32,
+    ) -> SplitOutputSpendDetails {
+        let (leaf_script, sequence) = match leaf {
+            SpendLeaf::Win => (
+                self.win_script.clone(),
+                Sequence::from_height(self.block_delta),
+            ),
+            SpendLeaf::Reclaim => (
+                self.reclaim_script.clone(),
+                // Safe: `SplitSpendInfo::new` rejects any `block_delta` whose double
+                // wouldn't fit a u16.
+                Sequence::from_height(2 * self.block_delta),
+            ),
+            SpendLeaf::Sellback => (self.sellback_script.clone(), Sequence::ZERO),
+        };
+
+        let control_block = self
+            .spend_info
+            .control_block(&(leaf_script.clone(), LeafVersion::TapScript))
+            .expect("leaf script cannot be missing");
+        let leaf_hash = TapLeafHash::from_script(&leaf_script, LeafVersion::TapScript);
+
+        SplitOutputSpendDetails {
+            outpoint,
+            script_pubkey: self.script_pubkey(),
+            payout_value: self.payout_value,
+            leaf_script,
+            control_block: control_block.serialize(),
+            sequence,
+            leaf_hash,
+            key_agg_ctx_untweaked: self.untweaked_ctx.clone(),
+            key_agg_ctx_tweaked: self.tweaked_ctx.clone(),
+            reschedule_info: Some(self.leaf_reschedule_info(leaf, confirmation_height)),
+        }
+    }
+
+    /// This split TX's dedicated CPFP anchor output's locking script, if one was
+    /// requested at construction. Callers should add this as a second, separate
+    /// output of the split TX alongside the payout output, funded with
+    /// [`P2TR_DUST_LIMIT`] — never folded into the payout output itself.
+    pub(crate) fn anchor_script_pubkey(&self) -> Option<ScriptBuf> {
+        self.anchor_script_pubkey.clone()
+    }
+
+    /// The fixed value the CPFP anchor output should be funded with, if one was
+    /// requested at construction.
+    pub(crate) fn anchor_value(&self) -> Option<Amount> {
+        self.anchor_script_pubkey.is_some().then_some(P2TR_DUST_LIMIT)
+    }
+
+    /// Builds a [`SplitOutputDescriptor::Anchor`] (wrapping [`AnchorSpendDetails`]) for this
+    /// split TX's dedicated CPFP anchor output, if one was included at construction, so a wallet without access to our
+    /// private fields can spend it via its key path on its own. `outpoint` is the
+    /// anchor output's own outpoint, distinct from the payout output's.
+    pub(crate) fn anchor_descriptor(&self, outpoint: OutPoint) -> Option<SplitOutputDescriptor> {
+        let script_pubkey = self.anchor_script_pubkey.clone()?;
+        Some(SplitOutputDescriptor::Anchor(AnchorSpendDetails {
+            outpoint,
+            script_pubkey,
+            value: P2TR_DUST_LIMIT,
+        }))
+    }
+
     // pub(crate) fn sighash_tx_win(&self)
 }
+
+/// A serializable descriptor of a single split TX output spend path, carrying
+/// everything an external wallet needs to build and witness the spend without holding
+/// a [`SplitSpendInfo`] directly. Each variant round-trips through serde so it can be
+/// handed off to a different process entirely.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum SplitOutputDescriptor {
+    /// Spendable by the player, with their signature and ticket preimage, after `delta`.
+    Win(SplitOutputSpendDetails),
+    /// Spendable by the market maker, with their signature, after `2*delta`.
+    Reclaim(SplitOutputSpendDetails),
+    /// Spendable by the market maker, with their signature and the payout preimage.
+    Sellback(SplitOutputSpendDetails),
+    /// Spendable by the market maker via the split TX's separate CPFP anchor output's
+    /// key path, to fee-bump a stuck spend of the payout output.
+    Anchor(AnchorSpendDetails),
+}
+
+/// Everything needed to build and witness one spend path of a split TX output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SplitOutputSpendDetails {
+    pub(crate) outpoint: OutPoint,
+    pub(crate) script_pubkey: ScriptBuf,
+    pub(crate) payout_value: Amount,
+    pub(crate) leaf_script: ScriptBuf,
+    pub(crate) control_block: Vec<u8>,
+    pub(crate) sequence: Sequence,
+    #[serde(with = "tap_leaf_hash_serde")]
+    pub(crate) leaf_hash: TapLeafHash,
+    #[serde(with = "key_agg_ctx_serde")]
+    pub(crate) key_agg_ctx_untweaked: KeyAggContext,
+    #[serde(with = "key_agg_ctx_serde")]
+    pub(crate) key_agg_ctx_tweaked: KeyAggContext,
+    /// CPFP re-broadcast scheduling metadata for this leaf. [`SplitSpendInfo::descriptors`]
+    /// always sets it; for the sellback leaf, which has no CSV requirement, the earliest
+    /// spendable height is the confirmation height itself.
+    pub(crate) reschedule_info: Option<LeafRescheduleInfo>,
+}
+
+/// Everything needed to spend a split TX's dedicated CPFP anchor output via its key
+/// path: a dust-value, single-key P2TR output the market maker can spend immediately
+/// to fee-bump a stuck win/reclaim/sellback TX, kept separate from the payout output
+/// so spending it can never touch the contract's actual value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct AnchorSpendDetails {
+    pub(crate) outpoint: OutPoint,
+    pub(crate) script_pubkey: ScriptBuf,
+    pub(crate) value: Amount,
+}
+
+/// Serializes a [`TapLeafHash`] as its raw 32 bytes rather than deriving through
+/// `bitcoin`'s own (de)serialization impl, which is only present when `bitcoin` is
+/// pulled in with its `serde` feature enabled. Going through `Hash::to_byte_array`/
+/// `from_byte_array` instead, which are always available, removes that dependency for this
+/// one field only: the other `bitcoin` types in [`SplitOutputSpendDetails`] (`OutPoint`,
+/// `ScriptBuf`, `Amount`, `Sequence`) still serialize through `bitcoin`'s own impls.
+mod tap_leaf_hash_serde {
+    use bitcoin::{hashes::Hash, taproot::TapLeafHash};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        leaf_hash: &TapLeafHash,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        leaf_hash.to_byte_array().serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TapLeafHash, D::Error> {
+        let bytes = <[u8; 32]>::deserialize(deserializer)?;
+        Ok(TapLeafHash::from_byte_array(bytes))
+    }
+}
+
+/// Serializes a [`KeyAggContext`] through its `BinaryEncoding` byte round trip
+/// rather than deriving through `musig2`'s own (de)serialization impl,
+/// which is only present when `musig2` is pulled in with its `serde` feature enabled.
+/// Going through the crate's always-available byte conversion instead keeps
+/// [`SplitOutputSpendDetails`] serializable regardless of how the consuming crate's
+/// `Cargo.toml` feature-gates its `musig2` dependency.
+mod key_agg_ctx_serde {
+    use musig2::{BinaryEncoding, KeyAggContext};
+    use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        ctx: &KeyAggContext,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ctx.to_bytes().serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<KeyAggContext, D::Error> {
+        let bytes = <Vec<u8>>::deserialize(deserializer)?;
+        KeyAggContext::from_bytes(&bytes).map_err(D::Error::custom)
+    }
+}
+
+/// One member of a [`SweepPackage`]: a split TX output's spend info, the outpoint it
+/// currently sits at, and the leaf path it will be swept through.
+#[derive(Debug, Clone)]
+pub(crate) struct SweepMember<'a> {
+    pub(crate) spend_info: &'a SplitSpendInfo,
+    pub(crate) outpoint: OutPoint,
+    pub(crate) leaf: SpendLeaf,
+}
+
+/// Per-input metadata needed to sighash and witness one input of a [`SweepPackage`]'s
+/// spending transaction.
+#[derive(Debug, Clone)]
+pub(crate) struct SweepInputInfo {
+    pub(crate) outpoint: OutPoint,
+    pub(crate) prevout_script_pubkey: ScriptBuf,
+    pub(crate) prevout_value: Amount,
+    pub(crate) sequence: Sequence,
+    pub(crate) leaf_script: ScriptBuf,
+    pub(crate) control_block: Vec<u8>,
+    pub(crate) leaf_hash: TapLeafHash,
+    pub(crate) key_agg_ctx_tweaked: KeyAggContext,
+    pub(crate) input_weight: InputWeightPrediction,
+}
+
+/// Aggregates many split TX outputs, all claimable via the same leaf path, into a
+/// single spending transaction, sharing one TX's fixed overhead and one destination
+/// output across every input rather than broadcasting one TX per split TX output.
+#[derive(Debug, Clone)]
+pub(crate) struct SweepPackage {
+    leaf: SpendLeaf,
+    inputs: Vec<SweepInputInfo>,
+    total_payout_value: Amount,
+    min_relative_locktime: u16,
+}
+
+impl SweepPackage {
+    /// Builds a [`SweepPackage`] from `members`, which must all spend through the same
+    /// [`SpendLeaf`] — a single TX can't mix a `delta`-CSV win input with a `2*delta`-CSV
+    /// reclaim input, since each leaf implies a different minimum `nSequence` and the
+    /// package as a whole can't be broadcast until every input's relative locktime is
+    /// satisfied.
+    pub(crate) fn build(members: &[SweepMember]) -> Result<SweepPackage, Error> {
+        let Some(first) = members.first() else {
+            return Err(Error::EmptySweepPackage);
+        };
+        let leaf = first.leaf;
+        if members.iter().any(|member| member.leaf != leaf) {
+            return Err(Error::MixedSweepSpendPaths);
+        }
+
+        let mut inputs = Vec::with_capacity(members.len());
+        let mut total_payout_value = Amount::ZERO;
+        let mut min_relative_locktime = 0u16;
+
+        for member in members {
+            let spend_info = member.spend_info;
+            let (leaf_script, sequence, relative_locktime, input_weight) = match leaf {
+                SpendLeaf::Win => (
+                    spend_info.win_script.clone(),
+                    Sequence::from_height(spend_info.block_delta),
+                    spend_info.block_delta,
+                    spend_info.input_weight_for_win_tx(),
+                ),
+                SpendLeaf::Reclaim => (
+                    spend_info.reclaim_script.clone(),
+                    // Safe: `SplitSpendInfo::new` rejects any `block_delta` whose double
+                    // wouldn't fit a u16, so every member's value here is already in range.
+                    Sequence::from_height(2 * spend_info.block_delta),
+                    2 * spend_info.block_delta,
+                    spend_info.input_weight_for_reclaim_tx(),
+                ),
+                SpendLeaf::Sellback => (
+                    spend_info.sellback_script.clone(),
+                    Sequence::ZERO,
+                    0,
+                    spend_info.input_weight_for_sellback_tx(),
+                ),
+            };
+
+            total_payout_value += spend_info.payout_value;
+            min_relative_locktime = min_relative_locktime.max(relative_locktime);
+
+            let control_block = spend_info
+                .spend_info
+                .control_block(&(leaf_script.clone(), LeafVersion::TapScript))
+                .expect("leaf script cannot be missing");
+            let leaf_hash = TapLeafHash::from_script(&leaf_script, LeafVersion::TapScript);
+
+            inputs.push(SweepInputInfo {
+                outpoint: member.outpoint,
+                prevout_script_pubkey: spend_info.script_pubkey(),
+                prevout_value: spend_info.payout_value,
+                sequence,
+                leaf_script,
+                control_block: control_block.serialize(),
+                leaf_hash,
+                key_agg_ctx_tweaked: spend_info.tweaked_ctx.clone(),
+                input_weight,
+            });
+        }
+
+        Ok(SweepPackage {
+            leaf,
+            inputs,
+            total_payout_value,
+            min_relative_locktime,
+        })
+    }
+
+    /// The leaf path every member of this package spends through.
+    pub(crate) fn leaf(&self) -> SpendLeaf {
+        self.leaf
+    }
+
+    /// Per-input sighash metadata, in input order, so each MuSig2/schnorr signature can
+    /// be produced and attached independently.
+    pub(crate) fn inputs(&self) -> &[SweepInputInfo] {
+        &self.inputs
+    }
+
+    /// The earliest relative locktime (in blocks since each input's confirmation) at
+    /// which every member of this package can be spent.
+    pub(crate) fn min_relative_locktime(&self) -> u16 {
+        self.min_relative_locktime
+    }
+
+    /// Computes [`LeafRescheduleInfo`] for this package, assuming every member
+    /// confirmed at `confirmation_height`.
+    pub(crate) fn reschedule_info(&self, confirmation_height: u32) -> LeafRescheduleInfo {
+        LeafRescheduleInfo {
+            earliest_spendable_height: confirmation_height
+                .saturating_add(self.min_relative_locktime as u32),
+            feerate_bump_step_sat_per_kwu: FEERATE_BUMP_STEP_SAT_PER_KWU,
+        }
+    }
+
+    /// Sums this package's members' [`InputWeightPrediction`]s into one aggregate weight,
+    /// suitable for sizing the single consolidated spending TX.
+    pub(crate) fn aggregate_input_weight(&self) -> Weight {
+        self.inputs
+            .iter()
+            .map(|input| input.input_weight.weight())
+            .sum()
+    }
+
+    /// Computes the single consolidated destination output's value after subtracting
+    /// the fee to confirm this package at the feerate `fee_estimator` returns for
+    /// `confirmation_target`, mirroring [`SplitSpendInfo::output_value_after_fee`] but
+    /// for the package's summed inputs.
+    pub(crate) fn output_value_after_fee(
+        &self,
+        fee_estimator: &dyn FeeEstimator,
+        confirmation_target: ConfirmationTarget,
+        extra_output_weight: Weight,
+    ) -> Result<Amount, Error> {
+        let feerate_sat_per_kwu = fee_estimator.sat_per_1000_weight(confirmation_target);
+        let total_weight = self.aggregate_input_weight()
+            + TX_FIXED_WEIGHT
+            + P2TR_OUTPUT_WEIGHT
+            + extra_output_weight;
+        let fee = Amount::from_sat(
+            total_weight
+                .to_wu()
+                .saturating_mul(feerate_sat_per_kwu)
+                .div_ceil(1000),
+        );
+
+        let value = self
+            .total_payout_value
+            .checked_sub(fee)
+            .unwrap_or(Amount::ZERO);
+        if value < P2TR_DUST_LIMIT {
+            return Err(Error::BelowDustLimit {
+                value,
+                dust_limit: P2TR_DUST_LIMIT,
+            });
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::{sha256, Hash};
+
+    use super::*;
+
+    struct FixedFeeEstimator(u64);
+
+    impl FeeEstimator for FixedFeeEstimator {
+        fn sat_per_1000_weight(&self, _confirmation_target: ConfirmationTarget) -> u64 {
+            self.0
+        }
+    }
+
+    fn test_pubkey(seckey_byte: u8) -> Point {
+        let seckey = secp256k1::SecretKey::from_slice(&[seckey_byte; 32]).unwrap();
+        Point::from(secp256k1::PublicKey::from_secret_key(
+            secp256k1::SECP256K1,
+            &seckey,
+        ))
+    }
+
+    fn test_outpoint(vout: u32) -> OutPoint {
+        OutPoint::new(bitcoin::Txid::all_zeros(), vout)
+    }
+
+    fn test_split_spend_info(block_delta: u16, include_cpfp_anchor: bool) -> SplitSpendInfo {
+        let market_maker = MarketMaker {
+            pubkey: test_pubkey(1),
+        };
+        let winner = Player {
+            pubkey: test_pubkey(2),
+            ticket_hash: sha256::Hash::hash(b"ticket").to_byte_array(),
+            payout_hash: sha256::Hash::hash(b"payout").to_byte_array(),
+        };
+        SplitSpendInfo::new(
+            winner,
+            &market_maker,
+            Amount::from_sat(100_000),
+            block_delta,
+            include_cpfp_anchor,
+        )
+        .expect("valid split spend info")
+    }
+
+    #[test]
+    fn output_value_after_fee_errors_below_dust_limit() {
+        let spend_info = test_split_spend_info(144, false);
+        let fee_estimator = FixedFeeEstimator(1_000_000);
+        let err = spend_info
+            .output_value_after_fee(
+                SpendLeaf::Win,
+                &fee_estimator,
+                ConfirmationTarget::Normal,
+                Weight::ZERO,
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::BelowDustLimit { .. }));
+    }
+
+    #[test]
+    fn output_value_after_fee_subtracts_fee_from_payout() {
+        let spend_info = test_split_spend_info(144, false);
+        let fee_estimator = FixedFeeEstimator(10);
+        let value = spend_info
+            .output_value_after_fee(
+                SpendLeaf::Reclaim,
+                &fee_estimator,
+                ConfirmationTarget::Normal,
+                Weight::ZERO,
+            )
+            .expect("value should clear the dust limit");
+        assert!(value < spend_info.payout_value());
+        assert!(value >= P2TR_DUST_LIMIT);
+    }
+
+    #[test]
+    fn sweep_package_build_errors_on_empty_members() {
+        let err = SweepPackage::build(&[]).unwrap_err();
+        assert!(matches!(err, Error::EmptySweepPackage));
+    }
+
+    #[test]
+    fn sweep_package_build_errors_on_mixed_spend_paths() {
+        let a = test_split_spend_info(144, false);
+        let b = test_split_spend_info(144, false);
+        let members = [
+            SweepMember {
+                spend_info: &a,
+                outpoint: test_outpoint(0),
+                leaf: SpendLeaf::Reclaim,
+            },
+            SweepMember {
+                spend_info: &b,
+                outpoint: test_outpoint(1),
+                leaf: SpendLeaf::Sellback,
+            },
+        ];
+        let err = SweepPackage::build(&members).unwrap_err();
+        assert!(matches!(err, Error::MixedSweepSpendPaths));
+    }
+
+    #[test]
+    fn sweep_package_aggregates_payout_values_and_fee() {
+        let a = test_split_spend_info(144, false);
+        let b = test_split_spend_info(144, false);
+        let members = [
+            SweepMember {
+                spend_info: &a,
+                outpoint: test_outpoint(0),
+                leaf: SpendLeaf::Reclaim,
+            },
+            SweepMember {
+                spend_info: &b,
+                outpoint: test_outpoint(1),
+                leaf: SpendLeaf::Reclaim,
+            },
+        ];
+        let package = SweepPackage::build(&members).expect("valid package");
+        assert_eq!(package.leaf(), SpendLeaf::Reclaim);
+        assert_eq!(package.inputs().len(), 2);
+        assert_eq!(package.min_relative_locktime(), 2 * 144);
+
+        let fee_estimator = FixedFeeEstimator(10);
+        let value = package
+            .output_value_after_fee(&fee_estimator, ConfirmationTarget::Normal, Weight::ZERO)
+            .expect("value should clear the dust limit");
+        assert!(value < a.payout_value() + b.payout_value());
+    }
+
+    #[test]
+    fn sweep_package_inputs_carry_control_block_and_leaf_hash() {
+        let a = test_split_spend_info(144, false);
+        let members = [SweepMember {
+            spend_info: &a,
+            outpoint: test_outpoint(0),
+            leaf: SpendLeaf::Reclaim,
+        }];
+        let package = SweepPackage::build(&members).expect("valid package");
+        let input = &package.inputs()[0];
+
+        // Without these, a wallet can't build a valid script-path witness
+        // (`control_block`) or compute the BIP341 sighash extension (`leaf_hash`).
+        assert!(!input.control_block.is_empty());
+        assert_eq!(
+            input.leaf_hash,
+            TapLeafHash::from_script(&input.leaf_script, LeafVersion::TapScript)
+        );
+    }
+
+    #[test]
+    fn sweep_package_min_relative_locktime_does_not_truncate_for_max_block_delta() {
+        let max_valid_block_delta = u16::MAX / 2;
+        let a = test_split_spend_info(max_valid_block_delta, false);
+        let members = [SweepMember {
+            spend_info: &a,
+            outpoint: test_outpoint(0),
+            leaf: SpendLeaf::Reclaim,
+        }];
+        let package = SweepPackage::build(&members).expect("valid package");
+        assert_eq!(package.min_relative_locktime(), 2 * max_valid_block_delta);
+    }
+
+    #[test]
+    fn descriptors_covers_every_payout_leaf_and_never_the_anchor() {
+        let spend_info = test_split_spend_info(144, true);
+        let descriptors = spend_info.descriptors(test_outpoint(0), 800_000);
+
+        // `descriptors` enumerates only the payout output's own leaves: the anchor
+        // output is a separate outpoint, reached only via `anchor_descriptor`.
+        assert_eq!(descriptors.len(), 3);
+        assert!(descriptors
+            .iter()
+            .any(|d| matches!(d, SplitOutputDescriptor::Win(_))));
+        assert!(descriptors
+            .iter()
+            .any(|d| matches!(d, SplitOutputDescriptor::Reclaim(_))));
+        assert!(descriptors
+            .iter()
+            .any(|d| matches!(d, SplitOutputDescriptor::Sellback(_))));
+        assert!(!descriptors
+            .iter()
+            .any(|d| matches!(d, SplitOutputDescriptor::Anchor(_))));
+    }
+
+    #[test]
+    fn descriptors_round_trip_through_serde() {
+        let spend_info = test_split_spend_info(144, true);
+        let mut descriptors = spend_info.descriptors(test_outpoint(0), 800_000);
+        descriptors.extend(spend_info.anchor_descriptor(test_outpoint(1)));
+
+        for descriptor in &descriptors {
+            let encoded = serde_json::to_vec(descriptor).expect("descriptor should serialize");
+            let decoded: SplitOutputDescriptor =
+                serde_json::from_slice(&encoded).expect("descriptor should deserialize");
+
+            match (descriptor, &decoded) {
+                (SplitOutputDescriptor::Win(a), SplitOutputDescriptor::Win(b))
+                | (SplitOutputDescriptor::Reclaim(a), SplitOutputDescriptor::Reclaim(b))
+                | (SplitOutputDescriptor::Sellback(a), SplitOutputDescriptor::Sellback(b)) => {
+                    assert_eq!(a.outpoint, b.outpoint);
+                    assert_eq!(a.sequence, b.sequence);
+                    assert_eq!(a.control_block, b.control_block);
+                    assert_eq!(a.reschedule_info, b.reschedule_info);
+                }
+                (SplitOutputDescriptor::Anchor(a), SplitOutputDescriptor::Anchor(b)) => {
+                    assert_eq!(a.outpoint, b.outpoint);
+                    assert_eq!(a.script_pubkey, b.script_pubkey);
+                    assert_eq!(a.value, b.value);
+                }
+                _ => panic!("descriptor variant changed across the round trip"),
+            }
+        }
+    }
+
+    #[test]
+    fn anchor_input_weight_present_only_when_requested() {
+        let without_anchor = test_split_spend_info(144, false);
+        assert!(without_anchor.anchor_input_weight().is_none());
+
+        let with_anchor = test_split_spend_info(144, true);
+        assert!(with_anchor.anchor_input_weight().is_some());
+    }
+
+    #[test]
+    fn anchor_descriptor_present_only_when_requested() {
+        let without_anchor = test_split_spend_info(144, false);
+        assert!(without_anchor.anchor_descriptor(test_outpoint(1)).is_none());
+        assert!(without_anchor.anchor_script_pubkey().is_none());
+        assert!(without_anchor.anchor_value().is_none());
+
+        let with_anchor = test_split_spend_info(144, true);
+        let anchor_descriptor = with_anchor
+            .anchor_descriptor(test_outpoint(1))
+            .expect("anchor descriptor should be present");
+        let SplitOutputDescriptor::Anchor(anchor_details) = anchor_descriptor else {
+            panic!("anchor_descriptor must return the Anchor variant");
+        };
+        assert_eq!(anchor_details.value, P2TR_DUST_LIMIT);
+        assert_eq!(with_anchor.anchor_value(), Some(P2TR_DUST_LIMIT));
+    }
+
+    #[test]
+    fn anchor_output_is_isolated_from_payout_value() {
+        let spend_info = test_split_spend_info(144, true);
+        let anchor_script_pubkey = spend_info
+            .anchor_script_pubkey()
+            .expect("anchor script pubkey should be present");
+
+        // The anchor must be a wholly separate output: a different locking script
+        // from the payout output, funded only at the dust limit. A single leaf
+        // shared with the payout output's tree would let whoever can spend it move
+        // the *entire* `payout_value`, not just a dust-sized fee-bump amount.
+        assert_ne!(anchor_script_pubkey, spend_info.script_pubkey());
+        assert_eq!(spend_info.anchor_value(), Some(P2TR_DUST_LIMIT));
+        assert!(P2TR_DUST_LIMIT < spend_info.payout_value());
+    }
+
+    #[test]
+    fn new_errors_when_doubled_block_delta_overflows_u16() {
+        let market_maker = MarketMaker {
+            pubkey: test_pubkey(1),
+        };
+        let winner = Player {
+            pubkey: test_pubkey(2),
+            ticket_hash: sha256::Hash::hash(b"ticket").to_byte_array(),
+            payout_hash: sha256::Hash::hash(b"payout").to_byte_array(),
+        };
+        let err = SplitSpendInfo::new(
+            winner,
+            &market_maker,
+            Amount::from_sat(100_000),
+            u16::MAX / 2 + 1,
+            false,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::BlockDeltaTooLarge { .. }));
+    }
+
+    #[test]
+    fn leaf_reschedule_info_accounts_for_csv_delay() {
+        let spend_info = test_split_spend_info(144, false);
+        let confirmation_height = 800_000;
+
+        let win = spend_info.leaf_reschedule_info(SpendLeaf::Win, confirmation_height);
+        let reclaim = spend_info.leaf_reschedule_info(SpendLeaf::Reclaim, confirmation_height);
+        let sellback = spend_info.leaf_reschedule_info(SpendLeaf::Sellback, confirmation_height);
+
+        assert_eq!(win.earliest_spendable_height, confirmation_height + 144);
+        assert_eq!(
+            reclaim.earliest_spendable_height,
+            confirmation_height + 2 * 144
+        );
+        assert_eq!(sellback.earliest_spendable_height, confirmation_height);
+    }
+}